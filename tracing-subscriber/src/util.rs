@@ -42,6 +42,32 @@ where
         dispatch::set_default(&self.into())
     }
 
+    /// Sets `self` as the [default subscriber] for the duration of `f`,
+    /// restoring the prior default when `f` returns (or panics).
+    ///
+    /// This is a shorthand for [`set_default`](Self::set_default) that takes
+    /// care of holding onto the returned [`DefaultGuard`](dispatch::DefaultGuard)
+    /// for the caller, which is useful for capturing output in tests:
+    ///
+    /// ```rust
+    /// use tracing_subscriber::util::SubscriberInitExt;
+    ///
+    /// # let subscriber = tracing_subscriber::fmt().finish();
+    /// subscriber.with_default(|| {
+    ///     // `subscriber` is the default collector inside this closure.
+    ///     tracing::info!("capture me");
+    /// });
+    /// // The previous default is restored here.
+    /// ```
+    ///
+    /// [default subscriber]: tracing::dispatch#setting-the-default-collector
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn with_default<T>(self, f: impl FnOnce() -> T) -> T {
+        let _guard = self.set_default();
+        f()
+    }
+
     /// Attempts to set `self` as the [global default subscriber] in the current
     /// scope, returning an error if one is already set.
     ///
@@ -59,7 +85,8 @@ where
         self,
         #[cfg(feature = "tracing-log")] with_logger: bool,
     ) -> Result<(), TryInitError> {
-        dispatch::set_global_default(self.into()).map_err(TryInitError::new)?;
+        dispatch::set_global_default(self.into())
+            .map_err(TryInitError::global_default_already_set)?;
 
         // Since we are setting the global default subscriber, we can
         // opportunistically go ahead and set its global max level hint as
@@ -72,12 +99,44 @@ where
                 // subscriber, so that we get its max level hint.
                 .with_max_level(tracing_core::LevelFilter::current().as_log())
                 .init()
-                .map_err(TryInitError::new)?;
+                .map_err(TryInitError::log_tracer_already_set)?;
         }
 
         Ok(())
     }
 
+    /// Attempts to set `self` as the [global default subscriber] in the
+    /// current scope, using `log_level` as the max level for the [`log`]
+    /// compatibility subscriber, independent of `self`'s max level hint.
+    ///
+    /// Unlike [`try_init`](Self::try_init), this does not tie the [`log`]
+    /// compatibility subscriber's max level to the `tracing` subscriber's
+    /// max-level hint. This is useful when the `log` facade should remain
+    /// more (or less) verbose than `tracing`, for example to keep `tracing`
+    /// quiet while still capturing verbose third-party `log` output.
+    ///
+    /// This method returns an error if a global default subscriber has
+    /// already been set, or if a `log` logger has already been set.
+    ///
+    /// [global default subscriber]: tracing::dispatch#setting-the-default-collector
+    /// [`log`]: https://crates.io/log
+    #[cfg(feature = "tracing-log")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tracing-log")))]
+    fn try_init_with_log_level(
+        self,
+        log_level: tracing_core::LevelFilter,
+    ) -> Result<(), TryInitError> {
+        dispatch::set_global_default(self.into())
+            .map_err(TryInitError::global_default_already_set)?;
+
+        tracing_log::LogTracer::builder()
+            .with_max_level(log_level.as_log())
+            .init()
+            .map_err(TryInitError::log_tracer_already_set)?;
+
+        Ok(())
+    }
+
     /// Attempts to set `self` as the [global default subscriber] in the current
     /// scope, panicking if this fails.
     ///
@@ -106,24 +165,74 @@ impl<T> SubscriberInitExt for T where T: Into<Dispatch> {}
 
 /// Error returned by [`try_init`](SubscriberInitExt::try_init) if a global default subscriber could not be initialized.
 pub struct TryInitError {
+    kind: ErrorKind,
+
     #[cfg(feature = "std")]
     inner: Box<dyn Error + Send + Sync + 'static>,
+}
 
-    #[cfg(not(feature = "std"))]
-    _p: (),
+/// The reason that [`TryInitError`] was returned.
+///
+/// This is returned by the [`TryInitError::kind`] method, and allows the
+/// caller to distinguish between the different reasons that initializing a
+/// global default subscriber can fail, without needing to downcast or
+/// pattern-match on the error's `Display` output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A global default trace dispatcher has already been set.
+    GlobalDefaultAlreadySet,
+
+    /// A `log` logger has already been set, so the [`tracing-log`] `LogTracer`
+    /// could not be initialized.
+    ///
+    /// [`tracing-log`]: https://crates.io/crates/tracing-log
+    #[cfg(feature = "tracing-log")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tracing-log")))]
+    LogTracerAlreadySet,
 }
 
 // ==== impl TryInitError ====
 
 impl TryInitError {
     #[cfg(feature = "std")]
-    fn new(e: impl Into<Box<dyn Error + Send + Sync + 'static>>) -> Self {
-        Self { inner: e.into() }
+    fn global_default_already_set(e: impl Into<Box<dyn Error + Send + Sync + 'static>>) -> Self {
+        Self {
+            kind: ErrorKind::GlobalDefaultAlreadySet,
+            inner: e.into(),
+        }
     }
 
     #[cfg(not(feature = "std"))]
-    fn new<T>(_: T) -> Self {
-        Self { _p: () }
+    fn global_default_already_set<T>(_: T) -> Self {
+        Self {
+            kind: ErrorKind::GlobalDefaultAlreadySet,
+        }
+    }
+
+    #[cfg(all(feature = "std", feature = "tracing-log"))]
+    fn log_tracer_already_set(e: impl Into<Box<dyn Error + Send + Sync + 'static>>) -> Self {
+        Self {
+            kind: ErrorKind::LogTracerAlreadySet,
+            inner: e.into(),
+        }
+    }
+
+    #[cfg(all(not(feature = "std"), feature = "tracing-log"))]
+    fn log_tracer_already_set<T>(_: T) -> Self {
+        Self {
+            kind: ErrorKind::LogTracerAlreadySet,
+        }
+    }
+
+    /// Returns the [`ErrorKind`] describing why this error was returned.
+    ///
+    /// This allows callers that initialize a subscriber defensively to match
+    /// on the cause of the failure &mdash; for example, treating
+    /// [`ErrorKind::GlobalDefaultAlreadySet`] as a no-op rather than
+    /// unwrapping an unstructured error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
     }
 }
 